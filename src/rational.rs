@@ -1,8 +1,10 @@
 use std::cmp::Ordering;
+use std::str::FromStr;
 use std::{fmt, ops};
 use malachite as mal;
 use malachite::num::arithmetic::traits::{Abs, DivRem, PowAssign, Sign as MalSign};
 use malachite::num::conversion::traits::{IsInteger, RoundingFrom, WrappingFrom};
+use malachite::num::logic::traits::SignificantBits;
 use malachite::rounding_modes::RoundingMode;
 use malachite::natural::conversion::from_primitive_int;
 use calcu_rs::expression::{CalcursType, Expr};
@@ -98,19 +100,224 @@ impl Rational {
         Float::new(num / denom)
     }
 
-    /// will calculate [self] to the power of an integer number.
+    /// reconstructs the *exact* dyadic value of a finite `f64`, the inverse of [Self::to_float].
     ///
-    /// if the exponent is (a/b) e.g non-int: we calculate the power to the int quotient of a/b
-    /// and return the remainder: (self^quot, rem).
+    /// decomposes the bits of `f` into `sign * mantissa * 2^exp`, where `mantissa` is the
+    /// 53-bit significand (implicit leading bit restored) and `exp` is the unbiased exponent
+    /// minus 52, then builds the rational directly from that.
     ///
-    /// returns the input if calculation was not possible
-    pub fn pow(mut self, mut rhs: Self) -> (Self, Self) {
+    /// returns [None] for NaN and infinities, [Some(Rational::zero())] for `+-0.0`.
+    pub fn from_f64(f: f64) -> Option<Self> {
+        if f.is_nan() || f.is_infinite() {
+            return None;
+        }
+        if f == 0.0 {
+            return Some(Rational::zero());
+        }
+
+        let bits = f.to_bits();
+        let is_neg = bits >> 63 == 1;
+        let biased_exp = (bits >> 52) & 0x7ff;
+        let frac = bits & 0xf_ffff_ffff_ffff;
+
+        // subnormals have no implicit leading bit and a fixed exponent
+        let (mantissa, exp): (u64, i64) = if biased_exp == 0 {
+            (frac, -1074)
+        } else {
+            (frac | (1 << 52), biased_exp as i64 - 1075)
+        };
+
+        let mantissa = mal::Natural::from(mantissa);
+
+        let mut r = if exp >= 0 {
+            mal::Rational::from_naturals(mantissa << exp as u64, NAT_ONE)
+        } else {
+            mal::Rational::from_naturals(mantissa, NAT_ONE << (-exp) as u64)
+        };
+
+        if is_neg {
+            r *= Rational::minus_one().0;
+        }
+
+        Some(Self(r))
+    }
+
+    /// best rational approximation of [self] whose denominator does not exceed `max_denom`.
+    ///
+    /// computed via the continued-fraction convergent recurrence: `a_k = floor(x_k)`,
+    /// `x_{k+1} = 1 / (x_k - a_k)`, with convergents `h_k = a_k*h_{k-1} + h_{k-2}` and
+    /// `k_k = a_k*k_{k-1} + k_{k-2}`. once the next convergent's denominator would exceed
+    /// `max_denom`, the largest semiconvergent that still fits is compared against the last
+    /// full convergent and whichever is closer to `self` (not to the shrinking remainder
+    /// `x_k`) is kept.
+    ///
+    /// every rational has a denominator of at least 1, so `max_denom == 0` is treated the
+    /// same as `max_denom == 1` instead of underflowing.
+    pub fn approximate(&self, max_denom: u64) -> Rational {
+        let bound = mal::Natural::from(max_denom.max(1));
+
+        if *self.0.denominator_ref() <= bound {
+            return self.clone();
+        }
+
+        let is_neg = self.is_neg();
+        let target = self.0.clone().abs();
+        let (mut num, mut den) = (self.0.numerator_ref().clone(), self.0.denominator_ref().clone());
+
+        let (mut h_prev2, mut h_prev1) = (NAT_ZERO, NAT_ONE);
+        let (mut k_prev2, mut k_prev1) = (NAT_ONE, NAT_ZERO);
+
+        loop {
+            let (a, rem) = num.clone().div_rem(den.clone());
+
+            let h = a.clone() * h_prev1.clone() + h_prev2.clone();
+            let k = a.clone() * k_prev1.clone() + k_prev2.clone();
+
+            if k > bound {
+                let room = bound.clone() - k_prev2.clone();
+                let a_max = if k_prev1 == NAT_ZERO {
+                    room
+                } else {
+                    room.div_rem(k_prev1.clone()).0
+                };
+
+                let semi_h = a_max.clone() * h_prev1.clone() + h_prev2.clone();
+                let semi_k = a_max * k_prev1.clone() + k_prev2.clone();
+
+                let semi = mal::Rational::from_naturals(semi_h.clone(), semi_k.clone());
+                let prev = mal::Rational::from_naturals(h_prev1.clone(), k_prev1.clone());
+
+                let (best_h, best_k) = if (semi - target.clone()).abs() <= (prev - target).abs() {
+                    (semi_h, semi_k)
+                } else {
+                    (h_prev1, k_prev1)
+                };
+
+                let mut r = mal::Rational::from_naturals(best_h, best_k);
+                if is_neg {
+                    r *= Rational::minus_one().0;
+                }
+                return Rational(r);
+            }
+
+            h_prev2 = h_prev1;
+            h_prev1 = h;
+            k_prev2 = k_prev1;
+            k_prev1 = k;
+
+            if rem == NAT_ZERO {
+                let mut r = mal::Rational::from_naturals(h_prev1, k_prev1);
+                if is_neg {
+                    r *= Rational::minus_one().0;
+                }
+                return Rational(r);
+            }
+
+            num = den;
+            den = rem;
+        }
+    }
+
+    /// rounds [self] to `dps` decimal places using the given [RoundingMode], staying an
+    /// exact [Rational].
+    ///
+    /// scales by `10^dps`, rounds to the nearest integer according to `mode`, then divides
+    /// back by `10^dps`. `dps == 0` collapses to plain integer rounding.
+    pub fn round_dps(&self, dps: usize, mode: RoundingMode) -> Rational {
+        let mut pow10 = mal::Natural::from(10u64);
+        pow10.pow_assign(dps as u64);
+        let scale = mal::Rational::from(pow10);
+
+        let scaled = self.0.clone() * scale.clone();
+        let (rounded, _) = mal::Integer::rounding_from(&scaled, mode);
+
+        Self(mal::Rational::from(rounded) / scale)
+    }
+
+    /// largest value `<= self` with at most `dps` decimal places.
+    pub fn floor(&self, dps: usize) -> Rational {
+        self.round_dps(dps, RoundingMode::Floor)
+    }
+
+    /// smallest value `>= self` with at most `dps` decimal places.
+    pub fn ceil(&self, dps: usize) -> Rational {
+        self.round_dps(dps, RoundingMode::Ceiling)
+    }
+
+    /// [self] truncated towards zero to `dps` decimal places.
+    pub fn trunc(&self, dps: usize) -> Rational {
+        self.round_dps(dps, RoundingMode::Down)
+    }
+
+    /// [self] rounded to the nearest value with `dps` decimal places.
+    pub fn round(&self, dps: usize) -> Rational {
+        self.round_dps(dps, RoundingMode::Nearest)
+    }
+
+    /// parses an integer `"42"` or a ratio `"num/den"` in the given `radix`.
+    ///
+    /// rejects a zero denominator and non-digit input with a [ParseRationalError] instead
+    /// of panicking. for decimal/scientific notation use [FromStr] instead, which only
+    /// makes sense in base 10.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseRationalError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ParseRationalError::InvalidRadix);
+        }
+
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseRationalError::Empty);
+        }
+
+        let (is_neg, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let (num, den) = match s.split_once('/') {
+            Some((n, d)) => (parse_natural_radix(n, radix)?, parse_natural_radix(d, radix)?),
+            None => (parse_natural_radix(s, radix)?, NAT_ONE),
+        };
+
+        if den == NAT_ZERO {
+            return Err(ParseRationalError::ZeroDenominator);
+        }
+
+        let mut r = mal::Rational::from_naturals(num, den);
+        if is_neg {
+            r *= Rational::minus_one().0;
+        }
+
+        Ok(Self(r))
+    }
+
+    /// will calculate [self] to the power of a rational number.
+    ///
+    /// returns `(coeff, radicand, exp)` such that the result equals `coeff * radicand^exp`;
+    /// every branch keeps to this contract rather than overloading what element two means.
+    ///
+    /// - integer exponents fold in directly: `a^n -> (a^n, 1, 1)`.
+    /// - for `a^(b/c)` with `b > c` (exponent `>= 1`), the integer quotient is applied to
+    ///   `a` and folded into `coeff`, and the remainder `rem/c` (`< 1`) is carried forward
+    ///   as the exponent still owed on the *original* `a`, so radical extraction below also
+    ///   runs on exponents like `3/2`, not only on bare `1/q`.
+    /// - if the (possibly reduced) remaining exponent is `1/q`, any perfect `q`-th power
+    ///   hiding in the base's numerator/denominator is pulled out into `coeff` as well: for
+    ///   each prime factor with multiplicity `m`, `floor(m/q)` copies move outside the
+    ///   radical and `m mod q` stay inside as the new `radicand`. factoring is capped by
+    ///   [MAX_FACTOR_BITS]; if the base is too large to factor cheaply, or there is no
+    ///   `q`-th power to extract, this is a no-op.
+    ///
+    /// e.g. `8^(1/2) -> (2, 2, 1/2)` (i.e. `2 * 2^(1/2)`) and `4^(3/2) -> (8, 1, 1/2)`
+    /// (i.e. `8 * 1^(1/2) = 8`).
+    /// returns `(1, self, rhs)` if no simplification was possible.
+    pub fn pow(mut self, mut rhs: Self) -> (Self, Self, Self) {
         if self.is_zero() && rhs.is_zero() {
             panic!("0^0");
         }
 
         if rhs.is_zero() {
-            return (Rational::one(), Rational::one());
+            return (Rational::one(), Rational::one(), Rational::one());
         }
 
         // inverse if exponent is negative
@@ -125,33 +332,140 @@ impl Rational {
             let exp = rhs.0.numerator_ref();
             if let Ok(exp) = u64::try_from(exp) {
                 self.0.pow_assign(exp);
-                return (self, Rational::one())
+                return (self, Rational::one(), Rational::one());
             } else {
-                return (self, rhs);
+                return (Rational::one(), self, rhs);
             }
         }
 
         // ensure that the exponent is < 1
-        // a^(b/c) -> ( b/c -> quot + rem ) -> a^quot * a^rem  // apply the quotient
+        // a^(b/c) -> ( b/c -> quot + rem ) -> a^quot * a^rem  // apply the quotient, carry
+        // `rem/c` (still owed on the original `a`) into the radical extraction below
+        let mut coeff = Rational::one();
         if rhs.0.numerator_ref() > rhs.0.denominator_ref() {
             let (num, den) = rhs.0.to_numerator_and_denominator();
-            let (quot, rem) = num.div_rem(den);
-            let rem_exp = Self(mal::Rational::from(rem));
+            let (quot, rem) = num.div_rem(den.clone());
+
+            match u64::try_from(&quot) {
+                Ok(apply_exp) => {
+                    let mut applied = self.clone();
+                    applied.0.pow_assign(apply_exp);
+                    coeff = applied;
+                }
+                Err(_) => return (Rational::one(), self, rhs),
+            }
 
-            if let Ok(apply_exp) = u64::try_from(&quot) {
-                self.0.pow_assign(apply_exp);
-                return (self, rem_exp)
+            rhs = Self(mal::Rational::from_naturals(rem, den));
+            if rhs.is_zero() {
+                return (coeff, Rational::one(), Rational::one());
             }
         }
 
-        // no change
-        (self, rhs)
+        // radical extraction: radicand^(1/q) -> extracted * residual^(1/q)
+        if rhs.0.numerator_ref() == &NAT_ONE && !self.is_zero() {
+            if let Ok(q) = u64::try_from(rhs.0.denominator_ref()) {
+                let is_neg = self.is_neg();
+
+                // even roots of negatives are not real
+                if !(is_neg && q % 2 == 0) {
+                    let (num, den) = self.0.clone().abs().to_numerator_and_denominator();
+
+                    if let (Some((num_coeff, num_res)), Some((den_coeff, den_res))) =
+                        (extract_radical(num, q), extract_radical(den, q))
+                    {
+                        if num_coeff != NAT_ONE || den_coeff != NAT_ONE {
+                            let mut extracted = mal::Rational::from_naturals(num_coeff, den_coeff);
+                            if is_neg {
+                                extracted *= Rational::minus_one().0;
+                            }
+                            coeff.0 *= extracted;
+                            let residual = mal::Rational::from_naturals(num_res, den_res);
+                            return (coeff, Self(residual), rhs);
+                        }
+                    }
+                }
+            }
+        }
+
+        // no change beyond any quotient already folded into `coeff`
+        (coeff, self, rhs)
     }
 }
 
 const NAT_ZERO: mal::Natural = mal::Natural::const_from(0);
 const NAT_ONE: mal::Natural = mal::Natural::const_from(1);
 
+/// numbers with more bits than this are left unfactored by [extract_radical] rather than
+/// trial-divided, so [Rational::pow]'s radical extraction stays cheap.
+///
+/// trial division costs `O(sqrt(n))`; 32 bits caps the worst case (a large prime) at
+/// ~2^16 divisions, which finishes in well under a millisecond. 64 bits would let a
+/// single `pow` call trial-divide up to ~2^32 times, which can hang for seconds.
+const MAX_FACTOR_BITS: u64 = 32;
+
+/// trial-divides `n` into `(prime, multiplicity)` pairs.
+///
+/// returns [None] if `n` is too large to factor cheaply (see [MAX_FACTOR_BITS]).
+fn trial_factor(mut n: mal::Natural) -> Option<Vec<(mal::Natural, u64)>> {
+    if n.significant_bits() > MAX_FACTOR_BITS {
+        return None;
+    }
+
+    let mut factors = Vec::new();
+    let mut p = mal::Natural::from(2u64);
+
+    while p.clone() * p.clone() <= n {
+        let mut mult = 0u64;
+        loop {
+            let (q, r) = n.clone().div_rem(p.clone());
+            if r != NAT_ZERO {
+                break;
+            }
+            n = q;
+            mult += 1;
+        }
+        if mult > 0 {
+            factors.push((p.clone(), mult));
+        }
+        p += NAT_ONE;
+    }
+
+    if n != NAT_ONE {
+        factors.push((n, 1));
+    }
+
+    Some(factors)
+}
+
+/// splits `n` into the rational coefficient and residual radicand of its `q`-th root,
+/// i.e. `n = coeff^q * residual` with `residual` free of any `q`-th power.
+///
+/// returns [None] if `n` is too large to factor cheaply (see [MAX_FACTOR_BITS]).
+fn extract_radical(n: mal::Natural, q: u64) -> Option<(mal::Natural, mal::Natural)> {
+    let factors = trial_factor(n)?;
+
+    let mut coeff = NAT_ONE;
+    let mut residual = NAT_ONE;
+
+    for (p, m) in factors {
+        let outside = m / q;
+        let inside = m % q;
+
+        if outside > 0 {
+            let mut f = p.clone();
+            f.pow_assign(outside);
+            coeff *= f;
+        }
+        if inside > 0 {
+            let mut f = p;
+            f.pow_assign(inside);
+            residual *= f;
+        }
+    }
+
+    Some((coeff, residual))
+}
+
 impl CalcursType for Rational {
     #[inline(always)]
     fn desc(&self) -> Item {
@@ -268,4 +582,122 @@ impl fmt::Display for Rational {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
     }
+}
+
+/// error returned by [FromStr] and [Rational::from_str_radix].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRationalError {
+    Empty,
+    InvalidDigit,
+    InvalidRadix,
+    ZeroDenominator,
+    ExponentOutOfRange,
+}
+
+impl fmt::Display for ParseRationalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRationalError::Empty => write!(f, "cannot parse rational from empty string"),
+            ParseRationalError::InvalidDigit => write!(f, "invalid digit found in string"),
+            ParseRationalError::InvalidRadix => write!(f, "radix must be between 2 and 36"),
+            ParseRationalError::ZeroDenominator => write!(f, "zero denominator"),
+            ParseRationalError::ExponentOutOfRange => {
+                write!(f, "decimal exponent out of range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseRationalError {}
+
+fn parse_natural_radix(s: &str, radix: u32) -> Result<mal::Natural, ParseRationalError> {
+    if s.is_empty() {
+        return Err(ParseRationalError::Empty);
+    }
+
+    let base = mal::Natural::from(radix);
+    let mut acc = NAT_ZERO;
+    for c in s.chars() {
+        let digit = c.to_digit(radix).ok_or(ParseRationalError::InvalidDigit)?;
+        acc = acc * base.clone() + mal::Natural::from(digit);
+    }
+    Ok(acc)
+}
+
+/// magnitudes above this are rejected by [FromStr]'s decimal/scientific parsing with
+/// [ParseRationalError::ExponentOutOfRange] rather than being folded into [pow10].
+///
+/// `10^100_000` already has a hundred thousand digits; a 12-character input like
+/// `"1e1000000000"` would otherwise materialize a ~400 MB [mal::Natural] from a single
+/// `parse::<i64>()` call, so the cap has to live well below `i64`'s range, not at it.
+const MAX_DECIMAL_SHIFT: i64 = 100_000;
+
+fn pow10(n: u64) -> mal::Natural {
+    let mut p = mal::Natural::from(10u64);
+    p.pow_assign(n);
+    p
+}
+
+/// parses a plain integer (`"42"`), a ratio (`"num/den"`), or a decimal / scientific
+/// literal (`"3.14"`, `"1.5e-3"`) into its *exact* rational value.
+///
+/// a decimal is converted exactly by counting its fractional digits and folding the
+/// exponent into a power-of-ten numerator or denominator, e.g. `"3.14" -> 314/100`
+/// (reduced), rather than going through a lossy `f64`.
+impl FromStr for Rational {
+    type Err = ParseRationalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseRationalError::Empty);
+        }
+
+        let (is_neg, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        if s.contains('/') {
+            let mut r = Rational::from_str_radix(s, 10)?;
+            if is_neg {
+                r.0 *= Rational::minus_one().0;
+            }
+            return Ok(r);
+        }
+
+        let (mantissa, exp) = match s.split_once(['e', 'E']) {
+            Some((m, e)) => (
+                m,
+                e.parse::<i64>().map_err(|_| ParseRationalError::InvalidDigit)?,
+            ),
+            None => (s, 0),
+        };
+
+        let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(ParseRationalError::InvalidDigit);
+        }
+
+        let num = parse_natural_radix(&format!("{int_part}{frac_part}"), 10)?;
+        let shift = (frac_part.len() as i64)
+            .checked_sub(exp)
+            .ok_or(ParseRationalError::ExponentOutOfRange)?;
+
+        if shift.unsigned_abs() > MAX_DECIMAL_SHIFT as u64 {
+            return Err(ParseRationalError::ExponentOutOfRange);
+        }
+
+        let mut r = if shift >= 0 {
+            mal::Rational::from_naturals(num, pow10(shift as u64))
+        } else {
+            mal::Rational::from_naturals(num * pow10((-shift) as u64), NAT_ONE)
+        };
+
+        if is_neg {
+            r *= Rational::minus_one().0;
+        }
+
+        Ok(Self(r))
+    }
 }
\ No newline at end of file